@@ -608,6 +608,24 @@ impl FromStr for RunPostLinkScripts {
     }
 }
 
+/// What `pixi global` does when an exposed executable has the same name as an
+/// executable that is already on the `PATH`.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExposedPathConflict {
+    /// Warn about the conflicting executables
+    #[default]
+    Warn,
+    /// Abort with an error
+    Error,
+}
+impl FromStr for ExposedPathConflict {
+    type Err = serde::de::value::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub struct Config {
@@ -707,6 +725,12 @@ pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_platform: Option<Platform>,
 
+    /// What `pixi global` does when an exposed executable has the same name as
+    /// an executable that is already on the `PATH`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exposed_path_conflict: Option<ExposedPathConflict>,
+
     //////////////////////
     // Deprecated fields //
     //////////////////////
@@ -741,6 +765,7 @@ impl Default for Config {
             proxy_config: ProxyConfig::default(),
             build: BuildConfig::default(),
             tool_platform: None,
+            exposed_path_conflict: None,
 
             // Deprecated fields
             change_ps1: None,
@@ -1265,6 +1290,7 @@ impl Config {
             "default-channels",
             "detached-environments",
             "experimental.use-environment-activation-cache",
+            "exposed-path-conflict",
             "max-concurrent-solves",
             "mirrors",
             "pinning-strategy",
@@ -1337,6 +1363,7 @@ impl Config {
             proxy_config: self.proxy_config.merge(other.proxy_config),
             build: self.build.merge(other.build),
             tool_platform: self.tool_platform.or(other.tool_platform),
+            exposed_path_conflict: other.exposed_path_conflict.or(self.exposed_path_conflict),
 
             // Deprecated fields that we can ignore as we handle them inside `shell.` field
             change_ps1: None,
@@ -1418,6 +1445,12 @@ impl Config {
         self.tool_platform.unwrap_or(Platform::current())
     }
 
+    /// What `pixi global` does when an exposed executable conflicts with an
+    /// executable on the `PATH`.
+    pub fn exposed_path_conflict(&self) -> ExposedPathConflict {
+        self.exposed_path_conflict.unwrap_or_default()
+    }
+
     pub fn get_proxies(&self) -> reqwest::Result<Vec<Proxy>> {
         if (self.proxy_config.https.is_none() && self.proxy_config.http.is_none())
             || *USE_PROXY_FROM_ENV
@@ -1520,6 +1553,12 @@ impl Config {
                     .transpose()
                     .into_diagnostic()?;
             }
+            "exposed-path-conflict" => {
+                self.exposed_path_conflict = value
+                    .map(|v| ExposedPathConflict::from_str(v.as_str()))
+                    .transpose()
+                    .into_diagnostic()?;
+            }
             key if key.starts_with("repodata-config") => {
                 if key == "repodata-config" {
                     self.repodata_config = value
@@ -2106,6 +2145,7 @@ UNUSED = "unused"
             proxy_config: ProxyConfig::default(),
             build: BuildConfig::default(),
             tool_platform: None,
+            exposed_path_conflict: Some(ExposedPathConflict::Error),
             // Deprecated keys
             change_ps1: None,
             force_activate: None,
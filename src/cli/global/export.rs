@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use fancy_display::FancyDisplay;
+use itertools::Itertools;
+use pixi_config::{Config, ConfigCli};
+
+use crate::global::{EnvironmentName, Project};
+
+/// Export the global environments to a manifest file
+///
+/// The exported file contains the dependencies, channels, exposed executables and shortcuts
+/// of the environments, and can be used to recreate them with `pixi global import`.
+///
+/// Example:
+///
+/// - `pixi global export global-backup.toml`
+/// - `pixi global export --environment python --environment rust tools.toml`
+#[derive(Parser, Debug)]
+#[clap(arg_required_else_help = true, verbatim_doc_comment)]
+pub struct Args {
+    /// The file the environments are exported to.
+    #[arg(value_name = "OUTPUT")]
+    output: PathBuf,
+
+    /// The environments to export, defaults to all environments.
+    /// Can be specified multiple times.
+    #[clap(short, long)]
+    environment: Vec<EnvironmentName>,
+
+    #[clap(flatten)]
+    config: ConfigCli,
+}
+
+pub async fn execute(args: Args) -> miette::Result<()> {
+    let config = Config::with_cli_config(&args.config);
+    let project = Project::discover_or_create()
+        .await?
+        .with_cli_config(config.clone());
+
+    if let Some(env_name) = args
+        .environment
+        .iter()
+        .find(|env_name| project.environment(env_name).is_none())
+    {
+        miette::bail!("Environment {} doesn't exist", env_name.fancy_display());
+    }
+
+    let mut manifest = project.manifest.clone();
+    if !args.environment.is_empty() {
+        let not_exported = manifest
+            .parsed
+            .envs
+            .keys()
+            .filter(|env_name| !args.environment.contains(env_name))
+            .cloned()
+            .collect_vec();
+        for env_name in not_exported {
+            manifest.remove_environment(&env_name)?;
+        }
+    }
+    manifest.path = args.output;
+    manifest.save().await?;
+
+    eprintln!(
+        "{}Exported {} environment(s) to {}",
+        console::style(console::Emoji("✔ ", "")).green(),
+        manifest.parsed.envs.len(),
+        manifest.path.display()
+    );
+    Ok(())
+}
@@ -1,11 +1,21 @@
+use std::path::{Path, PathBuf};
+
 use clap::Parser;
+use fancy_display::FancyDisplay;
 use itertools::Itertools;
-use miette::Context;
+use miette::{Context, IntoDiagnostic};
 use pixi_config::{Config, ConfigCli};
+use pixi_consts::consts;
 
 use crate::{
     cli::global::revert_environment_after_error,
-    global::{self, EnvironmentName, ExposedName, Mapping, StateChanges},
+    global::{
+        self, EnvDir, EnvironmentName, ExposedName, Mapping, StateChanges,
+        find_executables_for_many_records,
+        install::{create_prefix_trampolines, script_exec_mapping},
+        trampoline::{GlobalExecutable, Trampoline},
+    },
+    prefix::Prefix,
 };
 
 /// Add exposed binaries from an environment to your global environment
@@ -13,6 +23,7 @@ use crate::{
 /// Example:
 ///
 /// - `pixi global expose add python310=python3.10 python3=python3 --environment myenv`
+/// - `pixi global expose add python310=python3.10 --prefix /path/to/prefix`
 /// - `pixi global add --environment my_env pytest pytest-cov --expose pytest=pytest`
 #[derive(Parser, Debug)]
 #[clap(arg_required_else_help = true, verbatim_doc_comment)]
@@ -24,8 +35,13 @@ pub struct AddArgs {
     mappings: Vec<Mapping>,
 
     /// The environment to which the binaries should be exposed.
-    #[clap(short, long)]
-    environment: EnvironmentName,
+    #[clap(short, long, required_unless_present = "prefix")]
+    environment: Option<EnvironmentName>,
+
+    /// The prefix of an environment not managed by `pixi global` from which the binaries should be exposed.
+    /// These binaries are not recorded in the global manifest.
+    #[clap(long, conflicts_with = "environment")]
+    prefix: Option<PathBuf>,
 
     #[clap(flatten)]
     config: ConfigCli,
@@ -35,6 +51,9 @@ pub struct AddArgs {
 ///
 /// `pixi global expose remove python310 python3 --environment myenv`
 /// will remove the exposed names `python310` and `python3` from the environment `myenv`
+///
+/// `pixi global expose remove python310 --prefix /path/to/prefix`
+/// will remove the exposed name `python310` that was exposed from the prefix
 #[derive(Parser, Debug)]
 pub struct RemoveArgs {
     /// The exposed names that should be removed
@@ -42,6 +61,32 @@ pub struct RemoveArgs {
     #[arg(num_args = 1.., id = "EXPOSED_NAME")]
     exposed_names: Vec<ExposedName>,
 
+    /// The prefix from which the binaries were exposed with `pixi global expose add --prefix`.
+    #[clap(long)]
+    prefix: Option<PathBuf>,
+
+    #[clap(flatten)]
+    config: ConfigCli,
+}
+
+/// Rename an exposed binary in the global environment
+///
+/// `pixi global expose rename python310 py310 --environment myenv`
+/// will rename the exposed name `python310` to `py310` in the environment `myenv`
+#[derive(Parser, Debug)]
+pub struct RenameArgs {
+    /// The exposed name that should be renamed
+    #[arg(value_name = "OLD_NAME")]
+    old_name: ExposedName,
+
+    /// The new exposed name
+    #[arg(value_name = "NEW_NAME")]
+    new_name: ExposedName,
+
+    /// The environment that contains the exposed name.
+    #[clap(short, long)]
+    environment: EnvironmentName,
+
     #[clap(flatten)]
     config: ConfigCli,
 }
@@ -53,6 +98,9 @@ pub struct RemoveArgs {
 ///
 /// `pixi global expose remove python310 --environment myenv`
 /// will remove the exposed name `python310` from the environment `myenv`
+///
+/// `pixi global expose rename python310 py310 --environment myenv`
+/// will rename the exposed name `python310` to `py310` in the environment `myenv`
 #[derive(Parser, Debug)]
 #[clap(group(clap::ArgGroup::new("command")))]
 pub enum SubCommand {
@@ -60,6 +108,8 @@ pub enum SubCommand {
     Add(AddArgs),
     #[clap(name = "remove")]
     Remove(RemoveArgs),
+    #[clap(name = "rename")]
+    Rename(RenameArgs),
 }
 
 /// Expose some binaries
@@ -67,6 +117,7 @@ pub async fn execute(args: SubCommand) -> miette::Result<()> {
     match args {
         SubCommand::Add(args) => add(args).await?,
         SubCommand::Remove(args) => remove(args).await?,
+        SubCommand::Rename(args) => rename(args).await?,
     }
     Ok(())
 }
@@ -77,11 +128,19 @@ pub async fn add(args: AddArgs) -> miette::Result<()> {
         .await?
         .with_cli_config(config.clone());
 
+    if let Some(prefix) = &args.prefix {
+        return add_from_prefix(&args.mappings, prefix, &project_original).await;
+    }
+    let env_name = args
+        .environment
+        .clone()
+        .ok_or_else(|| miette::miette!("either `--environment` or `--prefix` is required"))?;
+
     async fn apply_changes(
         args: &AddArgs,
+        env_name: &EnvironmentName,
         project: &mut global::Project,
     ) -> Result<StateChanges, miette::Error> {
-        let env_name = &args.environment;
         let mut state_changes = StateChanges::new_with_env(env_name.clone());
         for mapping in &args.mappings {
             project.manifest.add_exposed_mapping(env_name, mapping)?;
@@ -92,7 +151,7 @@ pub async fn add(args: AddArgs) -> miette::Result<()> {
     }
 
     let mut project_modified = project_original.clone();
-    match apply_changes(&args, &mut project_modified).await {
+    match apply_changes(&args, &env_name, &mut project_modified).await {
         Ok(state_changes) => {
             project_modified.manifest.save().await?;
             state_changes.report();
@@ -100,7 +159,7 @@ pub async fn add(args: AddArgs) -> miette::Result<()> {
         }
         Err(err) => {
             if let Err(revert_err) =
-                revert_environment_after_error(&args.environment, &project_original).await
+                revert_environment_after_error(&env_name, &project_original).await
             {
                 tracing::warn!("Reverting of the operation failed");
                 tracing::info!("Reversion error: {:?}", revert_err);
@@ -116,6 +175,10 @@ pub async fn remove(args: RemoveArgs) -> miette::Result<()> {
         .await?
         .with_cli_config(config.clone());
 
+    if let Some(prefix) = &args.prefix {
+        return remove_from_prefix(&args.exposed_names, prefix, &project_original).await;
+    }
+
     async fn apply_changes(
         exposed_name: &ExposedName,
         env_name: &EnvironmentName,
@@ -166,3 +229,196 @@ pub async fn remove(args: RemoveArgs) -> miette::Result<()> {
     }
     Ok(())
 }
+
+/// Canonicalizes the prefix path and checks that it contains a conda environment.
+fn validate_prefix(prefix: &Path) -> miette::Result<PathBuf> {
+    let prefix_path = dunce::canonicalize(prefix)
+        .into_diagnostic()
+        .wrap_err_with(|| format!("Couldn't find prefix {}", prefix.display()))?;
+    if !prefix_path.join(consts::CONDA_META_DIR).is_dir() {
+        miette::bail!(
+            "{} is not a conda environment, it doesn't contain a '{}' directory",
+            prefix_path.display(),
+            consts::CONDA_META_DIR
+        );
+    }
+    Ok(prefix_path)
+}
+
+/// Exposes executables of a prefix that is not managed by the global manifest.
+/// The trampolines are written to the global bin directory directly.
+async fn add_from_prefix(
+    mappings: &[Mapping],
+    prefix: &Path,
+    project: &global::Project,
+) -> miette::Result<()> {
+    // A later trampoline would silently overwrite an earlier one with the same name
+    if let Some(duplicate) = mappings
+        .iter()
+        .map(Mapping::exposed_name)
+        .duplicates()
+        .next()
+    {
+        miette::bail!(
+            "Exposed name {} is given more than once",
+            duplicate.fancy_display()
+        );
+    }
+
+    let prefix_path = validate_prefix(prefix)?;
+    let prefix = Prefix::new(&prefix_path);
+    let executables =
+        find_executables_for_many_records(&prefix, &prefix.find_installed_packages()?);
+    let env_dir = EnvDir::from_path(prefix_path.clone());
+
+    let script_mapping = mappings
+        .iter()
+        .map(|mapping| {
+            let exposed_name = mapping.exposed_name();
+            if project
+                .manifest
+                .match_exposed_name_to_environment(exposed_name)
+                .is_ok()
+                || project
+                    .bin_dir
+                    .executable_trampoline_path(exposed_name)
+                    .exists()
+            {
+                miette::bail!(
+                    "Exposed name {} already exists",
+                    exposed_name.fancy_display()
+                );
+            }
+            script_exec_mapping(
+                exposed_name,
+                mapping.executable_name(),
+                executables.iter(),
+                &project.bin_dir,
+                &env_dir,
+            )
+        })
+        .collect::<miette::Result<Vec<_>>>()?;
+
+    match create_prefix_trampolines(&script_mapping, &prefix).await {
+        Ok(exposed_names) => {
+            for exposed_name in exposed_names {
+                pixi_progress::println!(
+                    "{}Exposed executable {} from prefix {}.",
+                    console::style(console::Emoji("✔ ", "")).green(),
+                    exposed_name.fancy_display(),
+                    prefix_path.display()
+                );
+            }
+            Ok(())
+        }
+        Err(err) => {
+            // None of the trampolines existed before, so everything that is there now was
+            // created by this operation
+            for mapping in &script_mapping {
+                let Ok(trampoline) = Trampoline::try_from(&mapping.global_script_path).await else {
+                    continue;
+                };
+                if let Err(revert_err) = GlobalExecutable::Trampoline(trampoline).remove().await {
+                    tracing::warn!("Reverting of the operation failed");
+                    tracing::info!("Reversion error: {:?}", revert_err);
+                }
+            }
+            Err(err)
+        }
+    }
+}
+
+/// Removes executables that were exposed from a prefix with `--prefix`.
+async fn remove_from_prefix(
+    exposed_names: &[ExposedName],
+    prefix: &Path,
+    project: &global::Project,
+) -> miette::Result<()> {
+    // The prefix may already be deleted, in which case the given path is matched as is
+    let prefix_path = dunce::canonicalize(prefix)
+        .or_else(|_| std::path::absolute(prefix))
+        .into_diagnostic()?;
+
+    let mut exposed_from_prefix = Vec::new();
+    for executable in project.bin_dir.executables().await? {
+        if !exposed_names.contains(&executable.exposed_name()) {
+            continue;
+        }
+        // Entries that can't be read are not trampolines created by `--prefix`
+        let Ok(original_exe) = executable.executable().await else {
+            continue;
+        };
+        if original_exe.starts_with(&prefix_path) {
+            exposed_from_prefix.push(executable);
+        }
+    }
+
+    // Check all names before removing anything, so that nothing is removed on error
+    for exposed_name in exposed_names {
+        if !exposed_from_prefix
+            .iter()
+            .any(|executable| &executable.exposed_name() == exposed_name)
+        {
+            miette::bail!(
+                "Exposed name {} is not exposed from prefix {}",
+                exposed_name.fancy_display(),
+                prefix_path.display()
+            );
+        }
+    }
+
+    for executable in exposed_from_prefix {
+        executable.remove().await?;
+        pixi_progress::println!(
+            "{}Removed exposed executable {} from prefix {}.",
+            console::style(console::Emoji("✔ ", "")).green(),
+            executable.exposed_name().fancy_display(),
+            prefix_path.display()
+        );
+    }
+    Ok(())
+}
+
+pub async fn rename(args: RenameArgs) -> miette::Result<()> {
+    let config = Config::with_cli_config(&args.config);
+    let mut project = global::Project::discover_or_create()
+        .await?
+        .with_cli_config(config.clone());
+
+    let env_name = &args.environment;
+    project
+        .manifest
+        .rename_exposed_name(env_name, &args.old_name, &args.new_name)
+        .wrap_err_with(|| format!("Couldn't rename exposed name {}", args.old_name))?;
+
+    // Move the trampoline instead of syncing the environment, which would
+    // reinstall it and recreate the trampoline
+    let trampoline_path = project.bin_dir.executable_trampoline_path(&args.old_name);
+    let trampoline = Trampoline::try_from(&trampoline_path)
+        .await
+        .wrap_err_with(|| {
+            format!(
+                "Couldn't find the exposed executable {}, run `pixi global sync` first",
+                args.old_name.fancy_display()
+            )
+        })?;
+    let renamed = trampoline.rename(args.new_name.clone()).await?;
+
+    if let Err(err) = project.manifest.save().await {
+        // Keep the trampoline in line with the manifest on disk
+        if let Err(revert_err) = renamed.rename(args.old_name.clone()).await {
+            tracing::warn!("Reverting of the operation failed");
+            tracing::info!("Reversion error: {:?}", revert_err);
+        }
+        return Err(err);
+    }
+
+    pixi_progress::println!(
+        "{}Renamed exposed executable {} to {} in environment {}.",
+        console::style(console::Emoji("✔ ", "")).green(),
+        args.old_name.fancy_display(),
+        args.new_name.fancy_display(),
+        env_name.fancy_display()
+    );
+    Ok(())
+}
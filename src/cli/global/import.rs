@@ -0,0 +1,123 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use fancy_display::FancyDisplay;
+use miette::Context;
+use pixi_config::{Config, ConfigCli};
+
+use crate::{
+    cli::global::revert_environment_after_error,
+    global::{
+        EnvChanges, EnvState, EnvironmentName, Project, StateChanges,
+        list::list_all_global_environments, project::Manifest,
+    },
+};
+
+/// Import global environments from a manifest file
+///
+/// Recreates the environments of a file written by `pixi global export`,
+/// installing their dependencies and exposing their executables.
+/// Environments that already exist are not overwritten.
+///
+/// Example:
+///
+/// - `pixi global import global-backup.toml`
+/// - `pixi global import --environment python global-backup.toml`
+#[derive(Parser, Debug)]
+#[clap(arg_required_else_help = true, verbatim_doc_comment)]
+pub struct Args {
+    /// The file the environments are imported from.
+    #[arg(value_name = "INPUT")]
+    input: PathBuf,
+
+    /// The environments to import, defaults to all environments of the file.
+    /// Can be specified multiple times.
+    #[clap(short, long)]
+    environment: Vec<EnvironmentName>,
+
+    #[clap(flatten)]
+    config: ConfigCli,
+}
+
+pub async fn execute(args: Args) -> miette::Result<()> {
+    let config = Config::with_cli_config(&args.config);
+    let project_original = Project::discover_or_create()
+        .await?
+        .with_cli_config(config.clone());
+
+    let source = Manifest::from_path(&args.input)
+        .wrap_err_with(|| format!("Couldn't read {}", args.input.display()))?;
+
+    let env_names: Vec<EnvironmentName> = if args.environment.is_empty() {
+        source.parsed.envs.keys().cloned().collect()
+    } else {
+        args.environment.clone()
+    };
+
+    // Fail before installing anything if an environment can't be imported
+    for env_name in &env_names {
+        if !source.parsed.envs.contains_key(env_name) {
+            miette::bail!(
+                "Environment {} doesn't exist in {}",
+                env_name.fancy_display(),
+                args.input.display()
+            );
+        }
+        if project_original.environment(env_name).is_some() {
+            miette::bail!(
+                "Environment {} already exists. Remove it with `pixi global uninstall {}` to import it.",
+                env_name.fancy_display(),
+                env_name
+            );
+        }
+    }
+
+    async fn apply_changes(
+        env_name: &EnvironmentName,
+        source: &Manifest,
+        project: &mut Project,
+    ) -> miette::Result<StateChanges> {
+        let mut state_changes = StateChanges::new_with_env(env_name.clone());
+        project.manifest.import_environment(env_name, source)?;
+        state_changes |= project.sync_environment(env_name, None).await?;
+        project.manifest.save().await?;
+        Ok(state_changes)
+    }
+
+    let mut env_changes = EnvChanges::default();
+    let mut last_updated_project = project_original;
+    for env_name in &env_names {
+        let mut project = last_updated_project.clone();
+        match apply_changes(env_name, &source, &mut project)
+            .await
+            .wrap_err_with(|| format!("Couldn't import {}", env_name.fancy_display()))
+        {
+            Ok(_) => {
+                env_changes
+                    .changes
+                    .insert(env_name.clone(), EnvState::Installed);
+            }
+            Err(err) => {
+                if let Err(revert_err) =
+                    revert_environment_after_error(env_name, &last_updated_project).await
+                {
+                    tracing::warn!("Reverting of the operation failed");
+                    tracing::info!("Reversion error: {:?}", revert_err);
+                }
+                return Err(err);
+            }
+        }
+        last_updated_project = project;
+    }
+
+    list_all_global_environments(
+        &last_updated_project,
+        Some(env_names),
+        Some(&env_changes),
+        None,
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
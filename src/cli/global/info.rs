@@ -0,0 +1,226 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use fancy_display::FancyDisplay;
+use itertools::Itertools;
+use miette::IntoDiagnostic;
+use pixi_config::{Config, ConfigCli};
+use rattler_conda_types::PackageName;
+use serde::Serialize;
+
+use crate::{
+    global::{EnvironmentName, Project, find_executables_for_many_records},
+    prefix::PrefixError,
+};
+
+/// Show information about a globally installed package
+///
+/// Prints the prefix of the environment the package is installed in,
+/// the installed version and build, the packages installed alongside it and the executables it exposes.
+///
+/// Example: `pixi global info python`
+#[derive(Parser, Debug)]
+#[clap(arg_required_else_help = true, verbatim_doc_comment)]
+pub struct Args {
+    /// The package to show information about.
+    #[arg(value_name = "PACKAGE")]
+    package: PackageName,
+
+    /// The environment the package is installed in.
+    /// Only required if the package is a dependency of multiple environments.
+    #[clap(short, long)]
+    environment: Option<EnvironmentName>,
+
+    /// Whether to show the output as JSON or not
+    #[arg(long)]
+    json: bool,
+
+    #[clap(flatten)]
+    config: ConfigCli,
+}
+
+#[derive(Serialize)]
+struct ExposedInfo {
+    name: String,
+    executable: String,
+    path: PathBuf,
+}
+
+#[derive(Serialize)]
+struct InstalledPackageInfo {
+    name: String,
+    version: String,
+    build: String,
+}
+
+#[derive(Serialize)]
+struct PackageInfo {
+    name: String,
+    environment: String,
+    prefix: PathBuf,
+    version: String,
+    build: String,
+    channel: Option<String>,
+    dependencies: Vec<InstalledPackageInfo>,
+    exposed: Vec<ExposedInfo>,
+}
+
+pub async fn execute(args: Args) -> miette::Result<()> {
+    let config = Config::with_cli_config(&args.config);
+    let project = Project::discover_or_create()
+        .await?
+        .with_cli_config(config.clone());
+
+    let package_name = args.package;
+
+    let env_name = match args.environment {
+        Some(env_name) => {
+            if project.environment(&env_name).is_none() {
+                miette::bail!("Environment {} doesn't exist", env_name.fancy_display());
+            }
+            env_name
+        }
+        None => {
+            let env_names = project
+                .environments()
+                .iter()
+                .filter(|(_, env)| env.dependencies.specs.contains_key(&package_name))
+                .map(|(env_name, _)| env_name.clone())
+                .collect_vec();
+            match env_names.as_slice() {
+                [] => miette::bail!(
+                    "Package {} is not a dependency of any global environment",
+                    package_name.as_source()
+                ),
+                [env_name] => env_name.clone(),
+                _ => miette::bail!(
+                    help = "Specify the environment with `--environment`",
+                    "Package {} is a dependency of multiple environments: {}",
+                    package_name.as_source(),
+                    env_names.iter().map(|env| env.fancy_display()).join(", ")
+                ),
+            }
+        }
+    };
+
+    let prefix = project.environment_prefix(&env_name).await?;
+    let mut installed_packages = prefix.find_installed_packages()?;
+    installed_packages.sort_by(|a, b| {
+        a.repodata_record
+            .package_record
+            .name
+            .cmp(&b.repodata_record.package_record.name)
+    });
+    let record = installed_packages
+        .iter()
+        .find(|record| record.repodata_record.package_record.name == package_name)
+        .ok_or_else(|| {
+            PrefixError::DesignatedPackageNotFound(
+                package_name.as_normalized().to_string(),
+                prefix.root().to_path_buf(),
+            )
+        })?;
+    let package_record = &record.repodata_record.package_record;
+
+    // Everything else installed in the prefix, with the resolved versions
+    let dependencies = installed_packages
+        .iter()
+        .map(|record| &record.repodata_record.package_record)
+        .filter(|installed| installed.name != package_name)
+        .map(|installed| InstalledPackageInfo {
+            name: installed.name.as_normalized().to_string(),
+            version: installed.version.to_string(),
+            build: installed.build.clone(),
+        })
+        .collect_vec();
+
+    // Only show the exposed executables that are provided by this package
+    let executables = find_executables_for_many_records(&prefix, std::slice::from_ref(record));
+    let exposed = project
+        .environment(&env_name)
+        .map(|env| {
+            env.exposed
+                .iter()
+                .filter(|mapping| {
+                    executables
+                        .iter()
+                        .any(|executable| executable.name == mapping.executable_name())
+                })
+                .map(|mapping| ExposedInfo {
+                    name: mapping.exposed_name().to_string(),
+                    executable: mapping.executable_relname().to_string(),
+                    path: project
+                        .bin_dir
+                        .executable_trampoline_path(mapping.exposed_name()),
+                })
+                .collect_vec()
+        })
+        .unwrap_or_default();
+
+    let info = PackageInfo {
+        name: package_record.name.as_normalized().to_string(),
+        environment: env_name.to_string(),
+        prefix: prefix.root().to_path_buf(),
+        version: package_record.version.to_string(),
+        build: package_record.build.clone(),
+        channel: record.repodata_record.channel.clone(),
+        dependencies,
+        exposed,
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&info).into_diagnostic()?);
+        return Ok(());
+    }
+
+    let bold = console::Style::new().bold().cyan();
+    println!(
+        "{:>13} {}",
+        bold.apply_to("Package"),
+        console::style(&info.name).green()
+    );
+    println!(
+        "{:>13} {}",
+        bold.apply_to("Environment"),
+        env_name.fancy_display()
+    );
+    println!("{:>13} {}", bold.apply_to("Prefix"), info.prefix.display());
+    println!(
+        "{:>13} {}",
+        bold.apply_to("Version"),
+        console::style(&info.version).blue()
+    );
+    println!("{:>13} {}", bold.apply_to("Build"), info.build);
+    if let Some(channel) = &info.channel {
+        println!("{:>13} {}", bold.apply_to("Channel"), channel);
+    }
+
+    println!("{:>13}", bold.apply_to("Dependencies"));
+    if info.dependencies.is_empty() {
+        println!("{:>13} {}", "", console::style("Nothing").dim());
+    }
+    for dependency in &info.dependencies {
+        println!(
+            "{:>13} {} {} {}",
+            "",
+            console::style(&dependency.name).green(),
+            console::style(&dependency.version).blue(),
+            dependency.build
+        );
+    }
+
+    println!("{:>13}", bold.apply_to("Exposes"));
+    if info.exposed.is_empty() {
+        println!("{:>13} {}", "", console::style("Nothing").dim());
+    }
+    for exposed in &info.exposed {
+        println!(
+            "{:>13} {} -> {}",
+            "",
+            console::style(&exposed.name).yellow(),
+            exposed.path.display()
+        );
+    }
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use std::{ops::Not, str::FromStr};
+use std::{collections::HashSet, ops::Not, str::FromStr};
 
 use indexmap::IndexMap;
 
@@ -11,13 +11,15 @@ use rattler_conda_types::{MatchSpec, NamedChannelOrUrl, Platform};
 use crate::{
     cli::global::{global_specs::GlobalSpecs, revert_environment_after_error},
     global::{
-        self, EnvChanges, EnvState, EnvironmentName, Mapping, Project, StateChange, StateChanges,
+        self, EnvChanges, EnvState, EnvironmentName, ExposedName, Mapping, Project, StateChange,
+        StateChanges,
         common::{NotChangedReason, contains_menuinst_document},
+        install::find_path_conflicts,
         list::list_all_global_environments,
         project::{ExposedType, NamedGlobalSpec},
     },
 };
-use pixi_config::{self, Config, ConfigCli};
+use pixi_config::{self, Config, ConfigCli, ExposedPathConflict};
 
 /// Installs the defined packages in a globally accessible location and exposes their command line applications.
 ///
@@ -76,6 +78,10 @@ pub struct Args {
     /// Specifies that no shortcuts should be created for the installed packages.
     #[arg(action, long, alias = "no-shortcut")]
     no_shortcuts: bool,
+
+    /// Skip checking whether exposed executables conflict with executables already on the `PATH`.
+    #[arg(action, long)]
+    force: bool,
 }
 
 pub async fn execute(args: Args) -> miette::Result<()> {
@@ -178,6 +184,16 @@ async fn setup_environment(
 ) -> miette::Result<StateChanges> {
     let mut state_changes = StateChanges::new_with_env(env_name.clone());
 
+    let previously_exposed: HashSet<ExposedName> = project
+        .environment(env_name)
+        .map(|env| {
+            env.exposed
+                .iter()
+                .map(|mapping| mapping.exposed_name().clone())
+                .collect()
+        })
+        .unwrap_or_default();
+
     if args.force_reinstall && project.environment(env_name).is_some() {
         state_changes |= project.remove_environment(env_name).await?;
     }
@@ -237,6 +253,10 @@ async fn setup_environment(
     // Sync exposed name
     sync_exposed_names(env_name, project, args).await?;
 
+    if !args.force {
+        check_path_conflicts(env_name, project, &previously_exposed)?;
+    }
+
     // Add shortcuts
     if !args.no_shortcuts {
         let prefix = project.environment_prefix(env_name).await?;
@@ -294,3 +314,63 @@ async fn sync_exposed_names(
     project.sync_exposed_names(env_name, expose_type).await?;
     Ok(())
 }
+
+/// Checks whether newly exposed names conflict with executables that are
+/// already on the `PATH`, and warns or errors depending on the configuration.
+fn check_path_conflicts(
+    env_name: &EnvironmentName,
+    project: &Project,
+    previously_exposed: &HashSet<ExposedName>,
+) -> miette::Result<()> {
+    let Some(environment) = project.environment(env_name) else {
+        return Ok(());
+    };
+
+    let conflicts = environment
+        .exposed
+        .iter()
+        .map(|mapping| mapping.exposed_name())
+        .filter(|exposed_name| !previously_exposed.contains(*exposed_name))
+        .flat_map(|exposed_name| {
+            find_path_conflicts(exposed_name, &project.bin_dir)
+                .into_iter()
+                .map(move |conflict| (exposed_name, conflict))
+        })
+        .collect_vec();
+
+    if conflicts.is_empty() {
+        return Ok(());
+    }
+
+    let message = conflicts
+        .iter()
+        .map(|(exposed_name, conflict)| {
+            if conflict.shadows_exposed {
+                format!(
+                    "{} is shadowed by {}",
+                    exposed_name.fancy_display(),
+                    conflict.path.display()
+                )
+            } else {
+                format!(
+                    "{} shadows {}",
+                    exposed_name.fancy_display(),
+                    conflict.path.display()
+                )
+            }
+        })
+        .join("\n\t");
+
+    match project.config().exposed_path_conflict() {
+        ExposedPathConflict::Warn => {
+            tracing::warn!(
+                "Exposed executables conflict with executables on the PATH:\n\t{message}\nUse `--force` to skip this check."
+            );
+            Ok(())
+        }
+        ExposedPathConflict::Error => Err(miette::miette!(
+            help = "Use `--force` to skip this check or `pixi global expose` to choose different names",
+            "Exposed executables conflict with executables on the PATH:\n\t{message}"
+        )),
+    }
+}
@@ -4,8 +4,11 @@ use crate::global::{self, EnvironmentName};
 
 mod add;
 mod edit;
+mod export;
 mod expose;
 mod global_specs;
+mod import;
+mod info;
 mod install;
 mod list;
 mod remove;
@@ -28,6 +31,7 @@ pub enum Command {
     Remove(remove::Args),
     #[clap(visible_alias = "ls")]
     List(list::Args),
+    Info(info::Args),
     #[clap(visible_alias = "s")]
     Sync(sync::Args),
     #[clap(visible_alias = "e")]
@@ -35,6 +39,8 @@ pub enum Command {
     Expose(expose::SubCommand),
     #[command(subcommand)]
     Shortcut(shortcut::SubCommand),
+    Export(export::Args),
+    Import(import::Args),
     Update(update::Args),
     #[command(hide = true)]
     Upgrade(upgrade::Args),
@@ -61,9 +67,12 @@ pub async fn execute(cmd: Args) -> miette::Result<()> {
         Command::Uninstall(args) => uninstall::execute(args).await?,
         Command::Remove(args) => remove::execute(args).await?,
         Command::List(args) => list::execute(args).await?,
+        Command::Info(args) => info::execute(args).await?,
         Command::Sync(args) => sync::execute(args).await?,
         Command::Expose(subcommand) => expose::execute(subcommand).await?,
         Command::Shortcut(subcommand) => shortcut::execute(subcommand).await?,
+        Command::Export(args) => export::execute(args).await?,
+        Command::Import(args) => import::execute(args).await?,
         Command::Update(args) => update::execute(args).await?,
         Command::Upgrade(args) => upgrade::execute(args).await?,
         Command::UpgradeAll(args) => upgrade_all::execute(args).await?,
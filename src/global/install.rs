@@ -15,7 +15,7 @@ use rattler_conda_types::{
     MatchSpec, Matches, PackageName, ParseStrictness, Platform, RepoDataRecord,
 };
 use rattler_shell::activation::prefix_path_entries;
-use std::{env, path::PathBuf, str::FromStr};
+use std::{collections::HashMap, env, path::PathBuf, str::FromStr};
 
 use fs_err::tokio as tokio_fs;
 
@@ -83,6 +83,23 @@ pub struct ScriptExecMapping {
     pub original_executable: PathBuf,
 }
 
+/// Runs the activation of the prefix and returns the `PATH` entries added by
+/// it, together with the remaining activation variables.
+async fn trampoline_activation(
+    prefix: &Prefix,
+) -> miette::Result<(String, HashMap<String, String>)> {
+    // Get PATH environment variables
+    let path_current = std::env::var("PATH").into_diagnostic()?;
+    let mut activation_variables = prefix.run_activation().await?;
+    let path_after_activation = activation_variables
+        .remove("PATH")
+        .or_else(|| activation_variables.remove("Path"))
+        .unwrap_or_else(|| path_current.clone());
+
+    let path_diff = path_diff(&path_current, &path_after_activation, prefix)?;
+    Ok((path_diff, activation_variables))
+}
+
 /// Create the executables trampolines by running the activation scripts,
 /// recording this information in the trampoline metadata,
 /// and saving both the trampoline and the metadata.
@@ -101,15 +118,7 @@ pub(crate) async fn create_executable_trampolines(
 
     let mut state_changes = StateChanges::default();
 
-    // Get PATH environment variables
-    let path_current = std::env::var("PATH").into_diagnostic()?;
-    let mut activation_variables = prefix.run_activation().await?;
-    let path_after_activation = activation_variables
-        .remove("PATH")
-        .or_else(|| activation_variables.remove("Path"))
-        .unwrap_or_else(|| path_current.clone());
-
-    let path_diff = path_diff(&path_current, &path_after_activation, prefix)?;
+    let (path_diff, activation_variables) = trampoline_activation(prefix).await?;
 
     for ScriptExecMapping {
         global_script_path,
@@ -197,6 +206,88 @@ pub(crate) async fn create_executable_trampolines(
     Ok(state_changes)
 }
 
+/// Creates trampolines for executables of a prefix that is not managed by the
+/// global manifest.
+///
+/// Returns the exposed names of the created trampolines.
+pub(crate) async fn create_prefix_trampolines(
+    mapped_executables: &[ScriptExecMapping],
+    prefix: &Prefix,
+) -> miette::Result<Vec<ExposedName>> {
+    let (path_diff, activation_variables) = trampoline_activation(prefix).await?;
+
+    let mut exposed_names = Vec::with_capacity(mapped_executables.len());
+    for ScriptExecMapping {
+        global_script_path,
+        original_executable,
+    } in mapped_executables
+    {
+        tracing::debug!("Create trampoline {}", global_script_path.display());
+        let exe = prefix.root().join(original_executable);
+        let metadata = Configuration::new(exe, path_diff.clone(), activation_variables.clone());
+
+        let parent_dir = global_script_path.parent().ok_or_else(|| {
+            miette::miette!(
+                "{} needs to have a parent directory",
+                global_script_path.display()
+            )
+        })?;
+        let exposed_name = Trampoline::name(global_script_path)?;
+
+        let trampoline = Trampoline::new(exposed_name.clone(), parent_dir.to_path_buf(), metadata);
+        trampoline.save().await?;
+        exposed_names.push(exposed_name);
+    }
+    Ok(exposed_names)
+}
+
+/// An executable on the `PATH` that has the same name as an exposed executable.
+#[derive(Debug)]
+pub(crate) struct PathConflict {
+    /// The path of the conflicting executable
+    pub path: PathBuf,
+    /// Whether the conflicting executable comes before the global bin directory
+    /// on the `PATH`, so that it shadows the exposed executable
+    pub shadows_exposed: bool,
+}
+
+/// Finds executables on the `PATH` with the same name as the exposed name that
+/// live outside of the global bin directory.
+pub(crate) fn find_path_conflicts(
+    exposed_name: &ExposedName,
+    bin_dir: &BinDir,
+) -> Vec<PathConflict> {
+    let Some(path_var) = env::var_os("PATH") else {
+        return Vec::new();
+    };
+    let search_dirs = env::split_paths(&path_var).collect_vec();
+    let bin_dir_position = search_dirs.iter().position(|dir| dir == bin_dir.path());
+
+    let Ok(found) = which::which_all(exposed_name.as_ref()) else {
+        return Vec::new();
+    };
+
+    found
+        .filter_map(|path| {
+            let parent = path.parent()?;
+            if parent == bin_dir.path() {
+                return None;
+            }
+            let position = search_dirs.iter().position(|dir| dir == parent);
+            let shadows_exposed = match (position, bin_dir_position) {
+                (Some(position), Some(bin_dir_position)) => position < bin_dir_position,
+                // The exposed executable can't be found through the `PATH` at all
+                (_, None) => true,
+                (None, Some(_)) => false,
+            };
+            Some(PathConflict {
+                path,
+                shadows_exposed,
+            })
+        })
+        .collect()
+}
+
 /// Compute the difference between two PATH variables (the entries split by `;` or `:`)
 fn path_diff(path_before: &str, path_after: &str, prefix: &Prefix) -> miette::Result<String> {
     // Split paths into vectors using platform-specific delimiter
@@ -134,6 +134,72 @@ impl Manifest {
         Ok(())
     }
 
+    /// Copies an environment, including its dependencies, exposed mappings
+    /// and shortcuts, from another manifest into this manifest
+    pub fn import_environment(
+        &mut self,
+        env_name: &EnvironmentName,
+        source: &Manifest,
+    ) -> miette::Result<()> {
+        let environment = source.parsed.envs.get(env_name).ok_or_else(|| {
+            miette::miette!(
+                "Environment {} doesn't exist in {}",
+                env_name.fancy_display(),
+                source.path.display()
+            )
+        })?;
+        if self.parsed.envs.contains_key(env_name) {
+            miette::bail!("Environment {} already exists.", env_name.fancy_display());
+        }
+
+        // Ensure exposed names and shortcuts stay unique
+        for mapping in &environment.exposed {
+            if self.exposed_name_already_exists_in_other_envs(env_name, mapping.exposed_name()) {
+                miette::bail!(
+                    "Exposed name {} already exists",
+                    mapping.exposed_name().fancy_display()
+                );
+            }
+        }
+        for shortcut in environment.shortcuts.iter().flatten() {
+            if self.shortcut_already_exists_in_other_envs(env_name, shortcut) {
+                miette::bail!(
+                    "Shortcut {} already exists",
+                    console::style(shortcut.as_normalized()).green()
+                );
+            }
+        }
+
+        let item = source
+            .document
+            .as_table()
+            .get("envs")
+            .and_then(|envs| envs.get(env_name.as_str()))
+            .ok_or_else(|| {
+                miette::miette!(
+                    "Environment {} doesn't exist in {}",
+                    env_name.fancy_display(),
+                    source.path.display()
+                )
+            })?;
+
+        // Update self.parsed
+        self.parsed
+            .envs
+            .insert(env_name.clone(), environment.clone());
+
+        // Update self.document
+        self.document
+            .get_or_insert_nested_table("envs")?
+            .insert(env_name.as_str(), item.clone());
+
+        tracing::debug!(
+            "Imported environment {} into toml document",
+            env_name.fancy_display()
+        );
+        Ok(())
+    }
+
     /// Adds a dependency to the manifest
     pub fn add_dependency(
         &mut self,
@@ -389,6 +455,88 @@ impl Manifest {
         Ok(())
     }
 
+    /// Renames an exposed name in the manifest, keeping the executable it points to
+    pub fn rename_exposed_name(
+        &mut self,
+        env_name: &EnvironmentName,
+        old_name: &ExposedName,
+        new_name: &ExposedName,
+    ) -> miette::Result<()> {
+        // Ensure the environment exists
+        let environment = self.parsed.envs.get(env_name).ok_or_else(|| {
+            miette::miette!("Environment {} doesn't exist", env_name.fancy_display())
+        })?;
+
+        if !environment
+            .exposed
+            .iter()
+            .any(|mapping| mapping.exposed_name() == old_name)
+        {
+            miette::bail!(
+                "Exposed name {} not found in environment {}",
+                old_name.fancy_display(),
+                env_name.fancy_display()
+            );
+        }
+
+        // Ensure the new exposed name is unique
+        if environment
+            .exposed
+            .iter()
+            .any(|mapping| mapping.exposed_name() == new_name)
+            || self.exposed_name_already_exists_in_other_envs(env_name, new_name)
+        {
+            miette::bail!("Exposed name {} already exists", new_name.fancy_display());
+        }
+        if self
+            .document
+            .get_nested_table(&format!("envs.{env_name}.exposed"))
+            .ok()
+            .and_then(|exposed_table| exposed_table.get(old_name.as_ref()))
+            .is_none()
+        {
+            miette::bail!("The exposed name {old_name} doesn't exist");
+        }
+
+        // Update self.parsed, keeping the position of the mapping
+        let environment = self
+            .parsed
+            .envs
+            .get_mut(env_name)
+            .ok_or_else(|| miette::miette!("This should be impossible"))?;
+        environment.exposed = environment
+            .exposed
+            .drain(..)
+            .map(|mapping| {
+                if mapping.exposed_name() == old_name {
+                    Mapping::new(new_name.clone(), mapping.executable_relname)
+                } else {
+                    mapping
+                }
+            })
+            .collect();
+
+        // Update self.document, rebuilding the table as keys can't be renamed in place
+        let exposed_table = self
+            .document
+            .get_or_insert_nested_table(&format!("envs.{env_name}.exposed"))?;
+        let entries = exposed_table
+            .iter()
+            .map(|(key, item)| (key.to_string(), item.clone()))
+            .collect::<Vec<_>>();
+        exposed_table.clear();
+        for (key, item) in entries {
+            if key == old_name.as_ref() {
+                exposed_table.insert(new_name.as_ref(), item);
+            } else {
+                exposed_table.insert(&key, item);
+            }
+        }
+
+        tracing::debug!("Renamed exposed name {old_name} to {new_name} in toml document");
+        Ok(())
+    }
+
     /// Removes all exposed mappings for a specific environment
     pub fn remove_all_exposed_mappings(
         &mut self,
@@ -842,6 +990,113 @@ mod tests {
         assert!(result.is_err())
     }
 
+    #[test]
+    fn test_rename_exposed_mapping() {
+        let mut manifest = Manifest::default();
+        let old_name = ExposedName::from_str("test_exposed").unwrap();
+        let new_name = ExposedName::from_str("test_renamed").unwrap();
+        let executable_name = "test_executable".to_string();
+        let mapping = Mapping::new(old_name.clone(), executable_name.clone());
+        let env_name = EnvironmentName::from_str("test-env").unwrap();
+
+        // Add environment and mapping
+        manifest.add_environment(&env_name, None).unwrap();
+        manifest.add_exposed_mapping(&env_name, &mapping).unwrap();
+
+        // Rename the exposed name
+        manifest
+            .rename_exposed_name(&env_name, &old_name, &new_name)
+            .unwrap();
+
+        // Check document
+        let exposed_table = manifest
+            .document
+            .get_or_insert_nested_table(&format!("envs.{env_name}.exposed"))
+            .unwrap();
+        assert!(exposed_table.get(old_name.as_ref()).is_none());
+        let actual_value = exposed_table
+            .get(new_name.as_ref())
+            .unwrap()
+            .as_str()
+            .unwrap();
+        assert_eq!(executable_name, actual_value);
+
+        // Check parsed
+        let exposed = &manifest.parsed.envs.get(&env_name).unwrap().exposed;
+        assert!(!exposed.iter().any(|map| map.exposed_name() == &old_name));
+        let actual_value = exposed
+            .iter()
+            .find(|map| map.exposed_name() == &new_name)
+            .unwrap()
+            .executable_relname();
+        assert_eq!(executable_name, actual_value);
+    }
+
+    #[test]
+    fn test_rename_exposed_mapping_keeps_order() {
+        let mut manifest = Manifest::from_str(
+            Path::new("pixi-global.toml"),
+            r#"
+[envs.test]
+channels = ["conda-forge"]
+dependencies = { python = "*" }
+exposed = { first = "python", second = "python", third = "python" }
+"#,
+        )
+        .unwrap();
+        let env_name = EnvironmentName::from_str("test").unwrap();
+
+        manifest
+            .rename_exposed_name(
+                &env_name,
+                &ExposedName::from_str("first").unwrap(),
+                &ExposedName::from_str("renamed").unwrap(),
+            )
+            .unwrap();
+
+        let parsed_names = manifest.parsed.envs[&env_name]
+            .exposed
+            .iter()
+            .map(|mapping| mapping.exposed_name().to_string())
+            .collect_vec();
+        assert_eq!(parsed_names, ["renamed", "second", "third"]);
+
+        let document_names = manifest
+            .document
+            .get_nested_table(&format!("envs.{env_name}.exposed"))
+            .unwrap()
+            .iter()
+            .map(|(key, _)| key.to_string())
+            .collect_vec();
+        assert_eq!(document_names, ["renamed", "second", "third"]);
+    }
+
+    #[test]
+    fn test_rename_exposed_mapping_existing_name() {
+        let mut manifest = Manifest::default();
+        let first_name = ExposedName::from_str("first").unwrap();
+        let second_name = ExposedName::from_str("second").unwrap();
+        let env_name = EnvironmentName::from_str("test-env").unwrap();
+
+        manifest.add_environment(&env_name, None).unwrap();
+        manifest
+            .add_exposed_mapping(
+                &env_name,
+                &Mapping::new(first_name.clone(), "first_executable".to_string()),
+            )
+            .unwrap();
+        manifest
+            .add_exposed_mapping(
+                &env_name,
+                &Mapping::new(second_name.clone(), "second_executable".to_string()),
+            )
+            .unwrap();
+
+        // Renaming onto an exposed name that is already taken should return an error
+        let result = manifest.rename_exposed_name(&env_name, &first_name, &second_name);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_add_environment_default_channel() {
         let mut manifest = Manifest::default();
@@ -871,6 +1126,83 @@ mod tests {
         assert_eq!(expected_channels, actual_channels);
     }
 
+    #[test]
+    fn test_import_environment() {
+        let source = Manifest::from_str(
+            Path::new("exported.toml"),
+            r#"
+[envs.python]
+channels = ["conda-forge"]
+dependencies = { python = "3.12.*" }
+exposed = { python = "python", py = "python" }
+"#,
+        )
+        .unwrap();
+        let mut manifest = Manifest::from_str(
+            Path::new("pixi-global.toml"),
+            r#"
+[envs.other]
+channels = ["conda-forge"]
+dependencies = { other = "*" }
+exposed = { other = "other" }
+"#,
+        )
+        .unwrap();
+        let env_name = EnvironmentName::from_str("python").unwrap();
+
+        manifest.import_environment(&env_name, &source).unwrap();
+
+        let imported = manifest.parsed.envs.get(&env_name).unwrap();
+        assert_eq!(imported.exposed, source.parsed.envs[&env_name].exposed);
+        let roundtrip =
+            Manifest::from_str(Path::new("pixi-global.toml"), manifest.document.to_string())
+                .unwrap();
+        assert_eq!(roundtrip.parsed.envs.len(), 2);
+        let imported = roundtrip.parsed.envs.get(&env_name).unwrap();
+        assert_eq!(imported.exposed, source.parsed.envs[&env_name].exposed);
+        assert_eq!(
+            imported.dependencies.specs.keys().collect_vec(),
+            source.parsed.envs[&env_name]
+                .dependencies
+                .specs
+                .keys()
+                .collect_vec()
+        );
+
+        // Importing the same environment twice fails
+        let err = manifest.import_environment(&env_name, &source).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_import_environment_existing_exposed_name() {
+        let source = Manifest::from_str(
+            Path::new("exported.toml"),
+            r#"
+[envs.python]
+channels = ["conda-forge"]
+dependencies = { python = "3.12.*" }
+exposed = { python = "python" }
+"#,
+        )
+        .unwrap();
+        let mut manifest = Manifest::from_str(
+            Path::new("pixi-global.toml"),
+            r#"
+[envs.python3]
+channels = ["conda-forge"]
+dependencies = { python = "3.13.*" }
+exposed = { python = "python" }
+"#,
+        )
+        .unwrap();
+        let env_name = EnvironmentName::from_str("python").unwrap();
+
+        let err = manifest.import_environment(&env_name, &source).unwrap_err();
+        assert!(err.to_string().contains("Exposed name"));
+        assert!(!manifest.parsed.envs.contains_key(&env_name));
+    }
+
     #[test]
     fn test_add_environment_given_channel() {
         let mut manifest = Manifest::default();
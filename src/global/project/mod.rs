@@ -965,6 +965,21 @@ impl Project {
                 env_name
             ))?;
 
+        // Executables exposed with `--prefix` aren't recorded in the manifest,
+        // so make sure they aren't silently overwritten
+        for (mapping, script) in environment.exposed.iter().zip(&script_mapping) {
+            if let Ok(trampoline) = Trampoline::try_from(&script.global_script_path).await {
+                let original_exe = trampoline.original_exe();
+                if !original_exe.starts_with(self.env_root.path()) {
+                    miette::bail!(
+                        "Exposed name {} already exists, it is exposed from {}",
+                        mapping.exposed_name().fancy_display(),
+                        original_exe.display()
+                    );
+                }
+            }
+        }
+
         tracing::debug!(
             "Exposing executables for environment {}",
             env_name.fancy_display()
@@ -339,6 +339,30 @@ impl Trampoline {
         Ok(())
     }
 
+    /// Renames the trampoline and its configuration in place, without
+    /// recreating either of them.
+    pub async fn rename(self, exposed_name: ExposedName) -> miette::Result<Self> {
+        let renamed = Trampoline::new(
+            exposed_name,
+            self.root_path.clone(),
+            self.configuration.clone(),
+        );
+        if renamed.path().exists() {
+            miette::bail!("{} already exists", renamed.path().display());
+        }
+
+        tokio_fs::rename(self.configuration(), renamed.configuration())
+            .await
+            .into_diagnostic()?;
+        if let Err(err) = tokio_fs::rename(self.path(), renamed.path()).await {
+            // Keep the configuration next to the trampoline it belongs to
+            let _ = tokio_fs::rename(renamed.configuration(), self.configuration()).await;
+            return Err(err).into_diagnostic();
+        }
+
+        Ok(renamed)
+    }
+
     /// Returns the decompressed trampoline binary
     pub fn decompressed_trampoline() -> &'static [u8] {
         // A static variable to hold the cached decompressed trampoline binary
@@ -516,4 +540,36 @@ mod tests {
         // Check if the metadata is the same
         assert_eq!(shared_metadata.len(), linked_metadata.len());
     }
+
+    // Test that renaming moves both the trampoline and its configuration
+    #[tokio::test]
+    async fn test_trampoline_rename() {
+        use super::*;
+        use tempfile::tempdir;
+
+        let dir = tempdir().unwrap();
+        let configuration = Configuration::new(
+            dir.path().join("original_exe"),
+            String::new(),
+            HashMap::new(),
+        );
+        let trampoline = Trampoline::new(
+            ExposedName::from_str("old_name").unwrap(),
+            dir.path().to_path_buf(),
+            configuration.clone(),
+        );
+        trampoline.save().await.unwrap();
+        let old_path = trampoline.path();
+        let old_configuration = trampoline.configuration();
+
+        let renamed = trampoline
+            .rename(ExposedName::from_str("new_name").unwrap())
+            .await
+            .unwrap();
+
+        assert!(!old_path.exists());
+        assert!(!old_configuration.exists());
+        let reloaded = Trampoline::try_from(&renamed.path()).await.unwrap();
+        assert_eq!(reloaded.configuration, configuration);
+    }
 }
@@ -2,7 +2,7 @@ use super::{IoConcurrencyLimit, LockFileDerivedData};
 use crate::{
     Workspace,
     environment::{CondaPrefixUpdated, CondaPrefixUpdater, PythonStatus},
-    prefix::Prefix,
+    prefix::{Prefix, PrefixError},
     workspace::grouped_environment::GroupedEnvironment,
 };
 use async_once_cell::OnceCell;
@@ -15,10 +15,9 @@ use pixi_manifest::{EnvironmentName, FeaturesExt};
 use pixi_record::PixiRecord;
 use pixi_spec::PixiSpec;
 use pixi_spec_containers::DependencyMap;
-use rattler_conda_types::PrefixRecord;
-use rattler_conda_types::{GenericVirtualPackage, PackageName};
+use rattler_conda_types::{GenericVirtualPackage, PackageName, PackageRecord, PrefixRecord};
 use rattler_lock::LockFile;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 impl Workspace {
     /// In platform-less mode, solve and install packages directly without a lock file
@@ -85,11 +84,14 @@ impl Workspace {
                 let prefix = Prefix::new(&env_dir);
                 let mut needs_update = true;
 
+                // Read the prefix once for both the check and the lock file data
+                let installed_packages = InstalledPackages::from_prefix(&prefix).ok();
+
                 if env_dir.exists() {
-                    if let Ok(installed_packages) = prefix.find_installed_packages() {
+                    if let Some(installed_packages) = &installed_packages {
                         // Check if installed packages satisfy the current dependencies
                         needs_update =
-                            !dependencies_satisfied(&pixi_dependencies, &installed_packages);
+                            !dependencies_satisfied(&pixi_dependencies, installed_packages);
                         if !needs_update {
                             tracing::info!(
                                 "Environment '{}' already satisfies requirements, skipping solve/install",
@@ -99,8 +101,10 @@ impl Workspace {
                     }
                 }
 
-                // Read the installed packages to build lock file data regardless
-                if let Ok(installed_packages) = prefix.find_installed_packages() {
+                // Build lock file data from the installed packages regardless
+                if let Some(installed_packages) = installed_packages {
+                    let mut installed_packages = installed_packages.into_records();
+                    sort_installed_packages(&mut installed_packages);
                     let mut builder = LockFile::builder();
 
                     // Set channels for the environment
@@ -208,7 +212,8 @@ impl Workspace {
                     let env_dir = environment.dir();
                     let prefix = Prefix::new(&env_dir);
 
-                    if let Ok(installed_packages) = prefix.find_installed_packages() {
+                    if let Ok(mut installed_packages) = prefix.find_installed_packages() {
+                        sort_installed_packages(&mut installed_packages);
                         let mut builder = LockFile::builder();
 
                         // Set channels for the environment
@@ -259,7 +264,8 @@ impl Workspace {
                 let prefix = Prefix::new(&env_dir);
 
                 if env_dir.exists() {
-                    if let Ok(installed_packages) = prefix.find_installed_packages() {
+                    if let Ok(mut installed_packages) = prefix.find_installed_packages() {
+                        sort_installed_packages(&mut installed_packages);
                         let mut builder = LockFile::builder();
 
                         // Set channels for the environment
@@ -334,17 +340,62 @@ impl Workspace {
     }
 }
 
+/// Sorts the installed packages by name, version and build so that the lock
+/// file doesn't depend on the order in which the prefix lists them.
+fn sort_installed_packages(installed_packages: &mut [PrefixRecord]) {
+    installed_packages.sort_by(|a, b| {
+        let a = &a.repodata_record.package_record;
+        let b = &b.repodata_record.package_record;
+        a.name
+            .cmp(&b.name)
+            .then_with(|| a.version.cmp(&b.version))
+            .then_with(|| a.build.cmp(&b.build))
+    });
+}
+
+/// The packages installed in a prefix, indexed by their name so that looking
+/// up an installed package doesn't require scanning all records.
+struct InstalledPackages {
+    records: Vec<PrefixRecord>,
+    by_name: HashMap<PackageName, usize>,
+}
+
+impl InstalledPackages {
+    /// Reads the installed packages of the prefix and indexes them.
+    fn from_prefix(prefix: &Prefix) -> Result<Self, PrefixError> {
+        Ok(Self::from_records(prefix.find_installed_packages()?))
+    }
+
+    fn from_records(records: Vec<PrefixRecord>) -> Self {
+        let by_name = records
+            .iter()
+            .enumerate()
+            .map(|(index, record)| (record.repodata_record.package_record.name.clone(), index))
+            .collect();
+        Self { records, by_name }
+    }
+
+    /// Returns the installed package with the given name.
+    fn get(&self, name: &PackageName) -> Option<&PackageRecord> {
+        self.by_name
+            .get(name)
+            .map(|&index| &self.records[index].repodata_record.package_record)
+    }
+
+    fn into_records(self) -> Vec<PrefixRecord> {
+        self.records
+    }
+}
+
 /// Check if the installed packages satisfy the given dependencies
 /// For now, this is a simple check that all required package names are present
 fn dependencies_satisfied(
     dependencies: &DependencyMap<PackageName, PixiSpec>,
-    installed_packages: &[PrefixRecord],
+    installed_packages: &InstalledPackages,
 ) -> bool {
     // For each dependency, check if there's a matching installed package by name
     for (dep_name, _dep_specs) in dependencies.iter() {
-        let satisfied = installed_packages
-            .iter()
-            .any(|installed| &installed.repodata_record.package_record.name == dep_name);
+        let satisfied = installed_packages.get(dep_name).is_some();
 
         if !satisfied {
             tracing::debug!(
@@ -361,3 +412,122 @@ fn dependencies_satisfied(
     );
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rattler_conda_types::{RepoDataRecord, VersionWithSource};
+    use std::str::FromStr;
+    use url::Url;
+
+    fn prefix_record(name: &str, version: &str) -> PrefixRecord {
+        prefix_record_with_build(name, version, "build_string")
+    }
+
+    fn prefix_record_with_build(name: &str, version: &str, build: &str) -> PrefixRecord {
+        let package_record = PackageRecord::new(
+            name.parse().unwrap(),
+            VersionWithSource::from_str(version).unwrap(),
+            build.to_string(),
+        );
+        let repodata_record = RepoDataRecord {
+            package_record,
+            file_name: format!("{name}-{version}-{build}.conda"),
+            url: Url::from_str(&format!("https://example.com/{name}-{version}.conda")).unwrap(),
+            channel: None,
+        };
+        PrefixRecord::from_repodata_record(
+            repodata_record,
+            None,
+            None,
+            vec![],
+            Default::default(),
+            None,
+        )
+    }
+
+    fn installed_packages(packages: &[(&str, &str)]) -> InstalledPackages {
+        InstalledPackages::from_records(
+            packages
+                .iter()
+                .map(|(name, version)| prefix_record(name, version))
+                .collect(),
+        )
+    }
+
+    fn dependencies(names: &[&str]) -> DependencyMap<PackageName, PixiSpec> {
+        let mut dependencies = DependencyMap::default();
+        for name in names {
+            dependencies.insert(name.parse().unwrap(), PixiSpec::default());
+        }
+        dependencies
+    }
+
+    #[test]
+    fn test_sort_installed_packages_is_stable_across_input_order() {
+        let installed = vec![
+            prefix_record("python", "3.12.0"),
+            prefix_record_with_build("numpy", "2.0.0", "py313"),
+            prefix_record("numpy", "1.26.0"),
+            prefix_record_with_build("numpy", "2.0.0", "py312"),
+            prefix_record("libzlib", "1.3.1"),
+        ];
+        let mut shuffled = installed.clone();
+        shuffled.swap(0, 3);
+        shuffled.swap(1, 4);
+
+        let key = |records: &[PrefixRecord]| {
+            records
+                .iter()
+                .map(|record| record.repodata_record.file_name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut first = installed;
+        let mut second = shuffled;
+        sort_installed_packages(&mut first);
+        sort_installed_packages(&mut second);
+
+        assert_eq!(key(&first), key(&second));
+        assert_eq!(
+            key(&first),
+            vec![
+                "libzlib-1.3.1-build_string.conda",
+                "numpy-1.26.0-build_string.conda",
+                "numpy-2.0.0-py312.conda",
+                "numpy-2.0.0-py313.conda",
+                "python-3.12.0-build_string.conda",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_installed_packages_lookup() {
+        let installed = installed_packages(&[("python", "3.12.0"), ("numpy", "2.0.0")]);
+
+        let numpy = installed
+            .get(&"numpy".parse::<PackageName>().unwrap())
+            .unwrap();
+        assert_eq!(numpy.version.to_string(), "2.0.0");
+        assert!(
+            installed
+                .get(&"scipy".parse::<PackageName>().unwrap())
+                .is_none()
+        );
+        assert_eq!(installed.into_records().len(), 2);
+    }
+
+    #[test]
+    fn test_dependencies_satisfied() {
+        let installed = installed_packages(&[("python", "3.12.0"), ("numpy", "2.0.0")]);
+
+        assert!(dependencies_satisfied(
+            &dependencies(&["python", "numpy"]),
+            &installed
+        ));
+        assert!(!dependencies_satisfied(
+            &dependencies(&["python", "scipy"]),
+            &installed
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+import json
+import os
 import platform
 import shutil
 import tomllib
@@ -358,6 +360,145 @@ def test_expose_basic(pixi: Path, tmp_pixi_workspace: Path, dummy_channel_1: str
     assert not dummy3.is_file()
 
 
+def test_expose_rename(pixi: Path, tmp_pixi_workspace: Path, dummy_channel_1: str) -> None:
+    env = {"PIXI_HOME": str(tmp_pixi_workspace)}
+    manifests = tmp_pixi_workspace.joinpath("manifests")
+    manifests.mkdir()
+    manifest = manifests.joinpath("pixi-global.toml")
+    toml = f"""
+    [envs.test]
+    channels = ["{dummy_channel_1}"]
+    dependencies = {{ dummy-a = "*" }}
+    exposed = {{ dummy-a = "dummy-a", dummy1 = "dummy-a" }}
+    """
+    manifest.write_text(toml)
+    dummy_a = tmp_pixi_workspace / "bin" / exec_extension("dummy-a")
+    dummy1 = tmp_pixi_workspace / "bin" / exec_extension("dummy1")
+    dummy2 = tmp_pixi_workspace / "bin" / exec_extension("dummy2")
+
+    verify_cli_command([pixi, "global", "sync"], env=env)
+    assert dummy_a.is_file()
+    assert dummy1.is_file()
+
+    # Rename dummy-a to dummy2
+    verify_cli_command(
+        [pixi, "global", "expose", "rename", "--environment=test", "dummy-a", "dummy2"],
+        env=env,
+    )
+    assert not dummy_a.is_file()
+    assert dummy2.is_file()
+    parsed_toml = tomllib.loads(manifest.read_text())
+    assert parsed_toml["envs"]["test"]["exposed"] == {"dummy1": "dummy-a", "dummy2": "dummy-a"}
+    # The renamed entry keeps its position and the trampoline configuration moves along
+    assert list(parsed_toml["envs"]["test"]["exposed"]) == ["dummy2", "dummy1"]
+    trampoline_configuration = tmp_pixi_workspace / "bin" / "trampoline_configuration"
+    assert not trampoline_configuration.joinpath("dummy-a.json").exists()
+    assert trampoline_configuration.joinpath("dummy2.json").is_file()
+
+    # Renaming onto an exposed name that already exists fails
+    verify_cli_command(
+        [pixi, "global", "expose", "rename", "--environment=test", "dummy2", "dummy1"],
+        ExitCode.FAILURE,
+        env=env,
+        stderr_contains="Exposed name dummy1 already exists",
+    )
+    assert dummy1.is_file()
+    assert dummy2.is_file()
+
+
+def test_expose_prefix(pixi: Path, tmp_pixi_workspace: Path, dummy_channel_1: str) -> None:
+    # Create a prefix that is not managed by the global manifest we expose into
+    other_home = tmp_pixi_workspace / "other-home"
+    verify_cli_command(
+        [pixi, "global", "install", "--channel", dummy_channel_1, "dummy-a"],
+        env={"PIXI_HOME": str(other_home)},
+    )
+    prefix = other_home / "envs" / "dummy-a"
+
+    env = {"PIXI_HOME": str(tmp_pixi_workspace)}
+    manifest = tmp_pixi_workspace / "manifests" / "pixi-global.toml"
+    dummy1 = tmp_pixi_workspace / "bin" / exec_extension("dummy1")
+
+    verify_cli_command(
+        [pixi, "global", "expose", "add", f"--prefix={prefix}", "dummy1=dummy-a"],
+        env=env,
+        stderr_contains="Exposed executable dummy1 from prefix",
+    )
+    assert dummy1.is_file()
+    # Prefix exposures aren't recorded in the manifest and survive a sync
+    assert "dummy1" not in manifest.read_text()
+    verify_cli_command([pixi, "global", "sync"], env=env)
+    assert dummy1.is_file()
+
+    # Environments of the manifest don't overwrite it
+    dummy1_configuration = tmp_pixi_workspace / "bin" / "trampoline_configuration" / "dummy1.json"
+    original_configuration = dummy1_configuration.read_text()
+    verify_cli_command(
+        [pixi, "global", "install", "--channel", dummy_channel_1, "dummy-a"],
+        env=env,
+    )
+    verify_cli_command(
+        [pixi, "global", "expose", "add", "--environment=dummy-a", "dummy1=dummy-a"],
+        ExitCode.FAILURE,
+        env=env,
+        stderr_contains="Exposed name dummy1 already exists",
+    )
+    assert "dummy1" not in manifest.read_text()
+    assert dummy1_configuration.read_text() == original_configuration
+
+    # Exposing the same name twice fails
+    verify_cli_command(
+        [pixi, "global", "expose", "add", f"--prefix={prefix}", "dummy1=dummy-a"],
+        ExitCode.FAILURE,
+        env=env,
+        stderr_contains="Exposed name dummy1 already exists",
+    )
+
+    # Exposing the same name twice within one call fails
+    verify_cli_command(
+        [
+            pixi,
+            "global",
+            "expose",
+            "add",
+            f"--prefix={prefix}",
+            "dummy2=dummy-a",
+            "dummy2=dummy-aa",
+        ],
+        ExitCode.FAILURE,
+        env=env,
+        stderr_contains="Exposed name dummy2 is given more than once",
+    )
+    assert not tmp_pixi_workspace.joinpath("bin", exec_extension("dummy2")).exists()
+
+    # Missing executables fail before any trampoline is written
+    verify_cli_command(
+        [pixi, "global", "expose", "add", f"--prefix={prefix}", "dummy2=dummy-a", "dummy3=dummy-b"],
+        ExitCode.FAILURE,
+        env=env,
+    )
+    assert not tmp_pixi_workspace.joinpath("bin", exec_extension("dummy2")).exists()
+
+    verify_cli_command(
+        [pixi, "global", "expose", "remove", f"--prefix={prefix}", "dummy1"],
+        env=env,
+    )
+    assert not dummy1.is_file()
+
+    # Exposures can still be removed after the prefix is deleted
+    verify_cli_command(
+        [pixi, "global", "expose", "add", f"--prefix={prefix}", "dummy1=dummy-a"],
+        env=env,
+    )
+    shutil.rmtree(prefix)
+    verify_cli_command(
+        [pixi, "global", "expose", "remove", f"--prefix={prefix}", "dummy1"],
+        env=env,
+        stderr_contains="Removed exposed executable dummy1 from prefix",
+    )
+    assert not dummy1.is_file()
+
+
 def test_expose_revert_working(pixi: Path, tmp_pixi_workspace: Path, dummy_channel_1: str) -> None:
     env = {"PIXI_HOME": str(tmp_pixi_workspace)}
     manifests = tmp_pixi_workspace.joinpath("manifests")
@@ -764,6 +905,45 @@ def test_install_twice(pixi: Path, tmp_pixi_workspace: Path, dummy_channel_1: st
     assert dummy_b.is_file()
 
 
+def test_install_path_conflict(pixi: Path, tmp_pixi_workspace: Path, dummy_channel_1: str) -> None:
+    system_bin = tmp_pixi_workspace / "system-bin"
+    system_bin.mkdir()
+    for name in ["dummy-a", "dummy-b"]:
+        system_executable = system_bin / bat_extension(name)
+        system_executable.write_text(f"echo system {name}")
+        system_executable.chmod(0o755)
+    env = {
+        "PIXI_HOME": str(tmp_pixi_workspace),
+        "PATH": os.pathsep.join([str(system_bin), os.environ["PATH"]]),
+    }
+
+    # By default, a conflict only results in a warning
+    verify_cli_command(
+        [pixi, "global", "install", "--channel", dummy_channel_1, "dummy-a"],
+        env=env,
+        stderr_contains=["Exposed executables conflict with executables on the PATH", "shadowed"],
+    )
+    assert (tmp_pixi_workspace / "bin" / exec_extension("dummy-a")).is_file()
+
+    # With the error mode, the installation is aborted
+    tmp_pixi_workspace.joinpath("config.toml").write_text('exposed-path-conflict = "error"')
+    verify_cli_command(
+        [pixi, "global", "install", "--channel", dummy_channel_1, "dummy-b"],
+        ExitCode.FAILURE,
+        env=env,
+        stderr_contains="Exposed executables conflict with executables on the PATH",
+    )
+    assert not (tmp_pixi_workspace / "bin" / exec_extension("dummy-b")).is_file()
+
+    # `--force` skips the check
+    verify_cli_command(
+        [pixi, "global", "install", "--channel", dummy_channel_1, "--force", "dummy-b"],
+        env=env,
+        stderr_excludes="Exposed executables conflict with executables on the PATH",
+    )
+    assert (tmp_pixi_workspace / "bin" / exec_extension("dummy-b")).is_file()
+
+
 def test_install_twice_with_same_env_name_as_expose(
     pixi: Path, tmp_pixi_workspace: Path, dummy_channel_1: str
 ) -> None:
@@ -1279,6 +1459,50 @@ def test_pixi_install_cleanup(
     assert package0_2_0.is_file()
 
 
+def test_export_import(pixi: Path, tmp_pixi_workspace: Path, dummy_channel_1: str) -> None:
+    env = {"PIXI_HOME": str(tmp_pixi_workspace)}
+    manifests = tmp_pixi_workspace.joinpath("manifests")
+    manifests.mkdir()
+    manifest = manifests.joinpath("pixi-global.toml")
+    toml = f"""
+    [envs.test]
+    channels = ["{dummy_channel_1}"]
+    dependencies = {{ dummy-a = "*" }}
+    exposed = {{ dummy1 = "dummy-a" }}
+
+    [envs.other]
+    channels = ["{dummy_channel_1}"]
+    dependencies = {{ dummy-b = "*" }}
+    """
+    manifest.write_text(toml)
+    verify_cli_command([pixi, "global", "sync"], env=env)
+
+    exported = tmp_pixi_workspace / "exported.toml"
+    verify_cli_command(
+        [pixi, "global", "export", "--environment=test", exported],
+        env=env,
+    )
+    parsed_export = tomllib.loads(exported.read_text())
+    assert list(parsed_export["envs"]) == ["test"]
+    assert parsed_export["envs"]["test"]["exposed"] == {"dummy1": "dummy-a"}
+
+    # Import into a fresh pixi home
+    other_home = tmp_pixi_workspace / "other-home"
+    other_env = {"PIXI_HOME": str(other_home)}
+    verify_cli_command([pixi, "global", "import", exported], env=other_env)
+    assert other_home.joinpath("bin", exec_extension("dummy1")).is_file()
+    parsed_toml = tomllib.loads(other_home.joinpath("manifests", "pixi-global.toml").read_text())
+    assert parsed_toml["envs"]["test"]["dependencies"] == {"dummy-a": "*"}
+
+    # Importing an environment that already exists fails
+    verify_cli_command(
+        [pixi, "global", "import", exported],
+        ExitCode.FAILURE,
+        env=other_env,
+        stderr_contains="Environment test already exists",
+    )
+
+
 def test_list(pixi: Path, tmp_pixi_workspace: Path, dummy_channel_1: str) -> None:
     env = {"PIXI_HOME": str(tmp_pixi_workspace)}
     manifests = tmp_pixi_workspace.joinpath("manifests")
@@ -1372,6 +1596,40 @@ def test_list_with_filter(pixi: Path, tmp_pixi_workspace: Path, dummy_channel_1:
     )
 
 
+def test_info(pixi: Path, tmp_pixi_workspace: Path, dummy_channel_1: str) -> None:
+    env = {"PIXI_HOME": str(tmp_pixi_workspace)}
+
+    verify_cli_command(
+        [pixi, "global", "install", "--channel", dummy_channel_1, "dummy-a==0.1.0"],
+        env=env,
+    )
+
+    verify_cli_command(
+        [pixi, "global", "info", "dummy-a"],
+        env=env,
+        stdout_contains=["dummy-a", "0.1.0", str(tmp_pixi_workspace / "envs" / "dummy-a")],
+    )
+
+    result = verify_cli_command([pixi, "global", "info", "dummy-a", "--json"], env=env)
+    info = json.loads(result.stdout)
+    assert info["name"] == "dummy-a"
+    assert info["environment"] == "dummy-a"
+    assert info["version"] == "0.1.0"
+    assert {exposed["name"] for exposed in info["exposed"]} == {"dummy-a", "dummy-aa"}
+    assert all(
+        {"name", "version", "build"} <= dependency.keys() for dependency in info["dependencies"]
+    )
+    assert "dummy-a" not in {dependency["name"] for dependency in info["dependencies"]}
+
+    # Packages that aren't a dependency of any environment can't be found
+    verify_cli_command(
+        [pixi, "global", "info", "dummy-b"],
+        ExitCode.FAILURE,
+        env=env,
+        stderr_contains="Package dummy-b is not a dependency of any global environment",
+    )
+
+
 # Test that we correctly uninstall the required packages
 # - Checking that the binaries are removed
 # - Checking that the non-requested to remove binaries are still there